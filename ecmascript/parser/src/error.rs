@@ -121,6 +121,7 @@ pub enum SyntaxError {
     ExpectedIdent,
     ExpctedSemi,
     DuplicateLabel(JsWord),
+    DuplicateParam(JsWord),
     AsyncGenerator,
     NonTopLevelImportExport,
     ImportExportInScript,
@@ -176,11 +177,16 @@ pub enum SyntaxError {
     TS1042,
     TS1047,
     TS1048,
+    TS1049,
+    TS1051,
+    TS1052,
+    TS1054,
     TS1056,
     TS1085,
     TS1092,
     TS1096,
     TS1098,
+    TS1100,
     TS1102,
     TS1105,
     TS1107,
@@ -200,6 +206,7 @@ pub enum SyntaxError {
     TS1093,
     TS1094,
     TS1196,
+    TS2300,
     TS2369,
     TS2371,
     TS2406,
@@ -295,6 +302,9 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             ExpectedIdent => "Expected ident".into(),
             ExpctedSemi => "Expected ';' or line break".into(),
             DuplicateLabel(ref label) => format!("Label {} is already declared", label).into(),
+            DuplicateParam(ref name) => {
+                format!("Duplicate parameter name {} is not allowed here", name).into()
+            }
             AsyncGenerator => "An async function cannot be generator".into(),
             NonTopLevelImportExport => "'import', and 'export' are not permitted here".into(),
             ImportExportInScript => {
@@ -369,8 +379,14 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
                 "Nullish coalescing operator(??) requires jsc.parser.Coalescing".into()
             }
 
+            TS1049 => "A 'set' accessor must have exactly one parameter".into(),
+            TS1051 => "A 'set' accessor cannot have an optional parameter".into(),
+            TS1052 => "A 'set' accessor parameter cannot have an initializer".into(),
+            TS1054 => "A 'get' accessor cannot have parameters".into(),
             TS1056 => "jsc.taraget should be es5 or upper to use getter / setter".into(),
+            TS1100 => "Invalid use of 'eval' or 'arguments' in strict mode".into(),
             TS1141 => "literal in an import type should be string literal".into(),
+            TS2300 => "Duplicate identifier".into(),
 
             // TODO:
             _ => format!("{:?}", e.error).into(),
@@ -1,4 +1,4 @@
-use super::{ident::MaybeOptionalIdentParser, *};
+use super::{ident::MaybeOptionalIdentParser, stmt::IsDirective, *};
 use crate::{error::SyntaxError, Tokens};
 use either::Either;
 use swc_atoms::js_word;
@@ -461,6 +461,25 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 let params = self.parse_constructor_params()?;
                 expect!(')');
 
+                // Class code is always strict.
+                {
+                    let mut ids = vec![];
+                    for param in &params {
+                        match *param {
+                            PatOrTsParamProp::Pat(ref pat) => collect_param_idents(pat, &mut ids),
+                            PatOrTsParamProp::TsParamProp(TsParamProp {
+                                param: TsParamPropParam::Ident(ref i),
+                                ..
+                            }) => ids.push(i),
+                            PatOrTsParamProp::TsParamProp(TsParamProp {
+                                param: TsParamPropParam::Assign(AssignPat { ref left, .. }),
+                                ..
+                            }) => collect_param_idents(left, &mut ids),
+                        }
+                    }
+                    self.check_duplicate_param_idents(&ids);
+                }
+
                 if self.syntax().typescript() && is!(':') {
                     let start = cur_pos!();
                     let type_ann = self.parse_ts_type_ann(true, start)?;
@@ -583,6 +602,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             {
                 // handle get foo(){} / set foo(v){}
                 let key = self.parse_class_prop_name()?;
+                let key_span = key.span();
 
                 if readonly {
                     unexpected!()
@@ -592,11 +612,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     js_word!("get") => self.make_method(
                         |p| {
                             let params = p.parse_formal_params()?;
-
-                            if params.len() != 0 {
-                                p.emit_err(key_span, SyntaxError::TS1094);
-                            }
-
+                            p.check_getter_params(key_span, &params);
                             Ok(params)
                         },
                         MakeMethodArgs {
@@ -615,17 +631,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     js_word!("set") => self.make_method(
                         |p| {
                             let params = p.parse_formal_params()?;
-
-                            if params.len() != 1 {
-                                p.emit_err(key_span, SyntaxError::TS1094);
-                            }
-
-                            if !params.is_empty() {
-                                if let Pat::Rest(..) = params[0] {
-                                    p.emit_err(params[0].span(), SyntaxError::RestPatInSetter);
-                                }
-                            }
-
+                            p.check_setter_params(key_span, &params);
                             Ok(params)
                         },
                         MakeMethodArgs {
@@ -854,7 +860,17 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 None
             };
 
-            let body: Option<_> = p.parse_fn_body(is_async, is_generator)?;
+            let body: Option<BlockStmt> = p.parse_fn_body(is_async, is_generator)?;
+
+            // A 'use strict' directive in the body applies to the parameters too.
+            if p.ctx().strict
+                || body
+                    .as_ref()
+                    .and_then(|body| body.stmts.first())
+                    .map_or(false, |stmt| stmt.is_use_strict())
+            {
+                p.check_duplicate_params(&params);
+            }
 
             if p.syntax().typescript() && body.is_none() {
                 // Declare functions cannot have assignment pattern in parameters
@@ -922,6 +938,56 @@ impl<'a, I: Tokens> Parser<'a, I> {
 }
 
 impl<'a, I: Tokens> Parser<'a, I> {
+    /// Reports names which are bound more than once by `params`.
+    pub(super) fn check_duplicate_params(&mut self, params: &[Pat]) {
+        let mut ids = vec![];
+        for pat in params {
+            collect_param_idents(pat, &mut ids);
+        }
+        self.check_duplicate_param_idents(&ids);
+    }
+
+    fn check_duplicate_param_idents(&mut self, ids: &[&Ident]) {
+        for (i, id) in ids.iter().enumerate() {
+            if ids[..i].iter().any(|prev| prev.sym == id.sym) {
+                self.emit_err(id.span, SyntaxError::DuplicateParam(id.sym.clone()));
+            }
+        }
+    }
+
+    /// Reports parameters of a getter. A typescript `this` parameter is not
+    /// counted.
+    pub(super) fn check_getter_params(&mut self, key_span: Span, params: &[Pat]) {
+        if !skip_this_param(params).is_empty() {
+            self.emit_err(key_span, SyntaxError::TS1054);
+        }
+    }
+
+    /// Reports parameters of a setter. A typescript `this` parameter is not
+    /// counted.
+    pub(super) fn check_setter_params(&mut self, key_span: Span, params: &[Pat]) {
+        let params = skip_this_param(params);
+
+        if params.len() != 1 {
+            self.emit_err(key_span, SyntaxError::TS1049);
+        }
+
+        if !params.is_empty() {
+            match params[0] {
+                Pat::Rest(..) => {
+                    self.emit_err(params[0].span(), SyntaxError::RestPatInSetter);
+                }
+                Pat::Ident(Ident { optional: true, .. }) if self.input.syntax().typescript() => {
+                    self.emit_err(params[0].span(), SyntaxError::TS1051);
+                }
+                Pat::Assign(..) if self.input.syntax().typescript() => {
+                    self.emit_err(params[0].span(), SyntaxError::TS1052);
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn make_method<F>(
         &mut self,
         parse_args: F,
@@ -1129,6 +1195,17 @@ impl<'a, I: Tokens> FnBodyParser<'a, Option<BlockStmt>> for Parser<'a, I> {
     }
 }
 
+/// Strips a leading typescript `this` parameter.
+pub(super) fn skip_this_param(params: &[Pat]) -> &[Pat] {
+    match params.first() {
+        Some(Pat::Ident(Ident {
+            sym: js_word!("this"),
+            ..
+        })) => &params[1..],
+        _ => params,
+    }
+}
+
 fn is_constructor(key: &Either<PrivateName, PropName>) -> bool {
     match *key {
         Either::Right(PropName::Ident(Ident {
@@ -1143,6 +1220,32 @@ fn is_constructor(key: &Either<PrivateName, PropName>) -> bool {
     }
 }
 
+/// Collects identifiers bound by a formal parameter.
+fn collect_param_idents<'a>(pat: &'a Pat, ids: &mut Vec<&'a Ident>) {
+    match *pat {
+        Pat::Ident(ref i) => ids.push(i),
+        Pat::Array(ArrayPat { ref elems, .. }) => {
+            for elem in elems.iter().filter_map(|e| e.as_ref()) {
+                collect_param_idents(elem, ids);
+            }
+        }
+        Pat::Object(ObjectPat { ref props, .. }) => {
+            for prop in props {
+                match *prop {
+                    ObjectPatProp::KeyValue(KeyValuePatProp { ref value, .. }) => {
+                        collect_param_idents(value, ids)
+                    }
+                    ObjectPatProp::Assign(AssignPatProp { ref key, .. }) => ids.push(key),
+                    ObjectPatProp::Rest(RestPat { ref arg, .. }) => collect_param_idents(arg, ids),
+                }
+            }
+        }
+        Pat::Rest(RestPat { ref arg, .. }) => collect_param_idents(arg, ids),
+        Pat::Assign(AssignPat { ref left, .. }) => collect_param_idents(left, ids),
+        Pat::Invalid(..) | Pat::Expr(..) => {}
+    }
+}
+
 struct MakeMethodArgs {
     start: BytePos,
     accessibility: Option<Accessibility>,
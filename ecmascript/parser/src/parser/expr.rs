@@ -1485,6 +1485,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
             // It is an early Reference Error if LeftHandSideExpression is neither
             // an ObjectLiteral nor an ArrayLiteral and
             // IsValidSimpleAssignmentTarget of LeftHandSideExpression is false.
+            if is_eval_or_arguments && self.ctx().strict {
+                self.emit_err(expr.span(), SyntaxError::TS1100);
+            }
+
             if !is_eval_or_arguments
                 && !expr.is_valid_simple_assignment_target(self.ctx().strict)
                 && should_deny(&expr, deny_call)
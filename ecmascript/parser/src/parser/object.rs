@@ -1,6 +1,6 @@
 //! Parser for object literal.
 
-use super::*;
+use super::{class_and_fn::skip_this_param, *};
 use swc_atoms::js_word;
 use swc_common::Spanned;
 
@@ -244,11 +244,7 @@ impl<'a, I: Tokens> ParseObject<'a, Box<Expr>> for Parser<'a, I> {
                             start,
                             |p| {
                                 let params = p.parse_formal_params()?;
-
-                                if params.len() != 0 {
-                                    p.emit_err(key_span, SyntaxError::TS1094);
-                                }
-
+                                p.check_getter_params(key_span, &params);
                                 Ok(params)
                             },
                             false,
@@ -286,16 +282,7 @@ impl<'a, I: Tokens> ParseObject<'a, Box<Expr>> for Parser<'a, I> {
                             start,
                             |p| {
                                 let params = p.parse_formal_params()?;
-
-                                if params.len() != 1 {
-                                    p.emit_err(key_span, SyntaxError::TS1094);
-                                }
-
-                                if !params.is_empty() {
-                                    if let Pat::Rest(..) = params[0] {
-                                        p.emit_err(params[0].span(), SyntaxError::RestPatInSetter);
-                                    }
-                                }
+                                p.check_setter_params(key_span, &params);
 
                                 if p.input.syntax().typescript()
                                     && p.input.target() == JscTarget::Es3
@@ -324,9 +311,12 @@ impl<'a, I: Tokens> ParseObject<'a, Box<Expr>> for Parser<'a, I> {
                                     span: span!(start),
                                     key,
                                     body,
-                                    param: params.into_iter().next().unwrap_or_else(|| {
-                                        Pat::Invalid(Invalid { span: key_span })
-                                    }),
+                                    param: skip_this_param(&params)
+                                        .first()
+                                        .cloned()
+                                        .unwrap_or_else(|| {
+                                            Pat::Invalid(Invalid { span: key_span })
+                                        }),
                                 })))
                             },
                         ),
@@ -737,6 +737,9 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
         params.push(last);
 
+        // Arrow functions never allow duplicate parameters.
+        self.check_duplicate_params(&params);
+
         Ok(params)
     }
 }
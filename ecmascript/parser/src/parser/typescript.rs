@@ -391,6 +391,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
             true,
         )?;
 
+        for (i, param) in params.iter().enumerate() {
+            if params[..i]
+                .iter()
+                .any(|prev| prev.name.sym == param.name.sym)
+            {
+                self.emit_err(param.name.span, SyntaxError::TS2300);
+            }
+        }
+
         Ok(TsTypeParamDecl {
             span: span!(start),
             params,
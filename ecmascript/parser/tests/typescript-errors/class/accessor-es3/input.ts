@@ -0,0 +1,6 @@
+class C {
+    get a() {
+        return 1;
+    }
+    set a(v: number) {}
+}
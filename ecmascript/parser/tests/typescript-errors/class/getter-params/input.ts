@@ -0,0 +1,5 @@
+class C {
+    get a(v) {
+        return 1;
+    }
+}
@@ -0,0 +1,3 @@
+class C {
+    set a() {}
+}
@@ -0,0 +1,6 @@
+class C {
+    set a(v?) {}
+    set b(v = 1) {}
+    set c(this: C, v?) {}
+    set d(this: C, v = 1) {}
+}
@@ -0,0 +1,3 @@
+class C {
+    set a(v, w) {}
+}
@@ -0,0 +1,18 @@
+function f(a, b, a) {}
+
+function g(a, [b, { c, d: a }], ...b) {}
+
+class C {
+    constructor(a, a) {}
+    m(x, x = 1) {}
+}
+
+class D {
+    constructor(private a, a) {}
+}
+
+const o = {
+    m(y, y) {},
+};
+
+const h = (z, z) => {};
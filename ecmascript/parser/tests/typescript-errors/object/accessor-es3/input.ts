@@ -0,0 +1,6 @@
+const o = {
+    get a() {
+        return 1;
+    },
+    set a(v: number) {},
+};
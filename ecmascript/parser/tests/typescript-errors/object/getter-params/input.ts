@@ -0,0 +1,5 @@
+const o = {
+    get a(v) {
+        return 1;
+    },
+};
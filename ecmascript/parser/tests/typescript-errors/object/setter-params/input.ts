@@ -0,0 +1,6 @@
+const o = {
+    set a(v?) {},
+    set b(v = 1) {},
+    set c(this: {}, v?) {},
+    set d(this: {}, v = 1) {},
+};
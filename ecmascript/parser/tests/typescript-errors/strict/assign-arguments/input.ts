@@ -0,0 +1,4 @@
+arguments = 1;
+eval = 1;
+arguments++;
+--eval;
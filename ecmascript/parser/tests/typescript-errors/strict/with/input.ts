@@ -0,0 +1,2 @@
+with (o) {
+}
@@ -0,0 +1,9 @@
+function f<T, T>(x: T) {}
+
+class C<A, B, A> {
+    m<U, U>() {}
+}
+
+interface I<K, K> {}
+
+type Alias<V, V> = V;
@@ -159,7 +159,11 @@ where
                 decorators: true,
                 ..Default::default()
             }),
-            JscTarget::Es2015,
+            if file_name.components().any(|c| c.as_os_str() == "accessor-es3") {
+                JscTarget::Es3
+            } else {
+                JscTarget::Es2015
+            },
             (&*fm).into(),
             None,
         );
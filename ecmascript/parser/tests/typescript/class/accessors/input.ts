@@ -0,0 +1,10 @@
+class C {
+    get a(): number {
+        return 1;
+    }
+    set a(v: number) {}
+    get b(this: C): number {
+        return 1;
+    }
+    set b(this: C, v: number) {}
+}
@@ -0,0 +1,10 @@
+const o = {
+    get a(): number {
+        return 1;
+    },
+    set a(v: number) {},
+    get b(this: {}): number {
+        return 1;
+    },
+    set b(this: {}, v: number) {},
+};
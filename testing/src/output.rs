@@ -2,7 +2,7 @@ use crate::paths;
 use std::{
     fmt,
     fs::{create_dir_all, remove_file, File},
-    io::Read,
+    io::{self, Read, Write},
     ops::Deref,
     path::Path,
 };
@@ -53,6 +53,9 @@ impl NormalizedOutput {
     /// If output differs, prints actual stdout/stderr to
     /// `CARGO_MANIFEST_DIR/target/swc-test-results/ui/$rel_path` where
     /// `$rel_path`: `path.strip_prefix(CARGO_MANIFEST_DIR)`
+    ///
+    /// If `UPDATE_BASELINES=1` is set, a mismatch is not an error: `path` is
+    /// rewritten with the actual output (or removed if the output is empty).
     pub fn compare_to_file<P>(self, path: P) -> Result<(), Diff>
     where
         P: AsRef<Path>,
@@ -79,6 +82,19 @@ impl NormalizedOutput {
             let _ = remove_file(path_for_actual);
             return Ok(());
         }
+        if update_baselines() {
+            if self.0.is_empty() {
+                let _ = remove_file(path);
+            } else {
+                crate::write_to_file(&path, &self.0);
+            }
+
+            // Written to the handle directly because libtest captures
+            // `eprintln!` of passing tests.
+            let _ = writeln!(io::stderr(), "Updated baseline: {}", path.display());
+            return Ok(());
+        }
+
         create_dir_all(path_for_actual.parent().unwrap()).expect("failed to run `mkdir -p`");
         // ::write_to_file(&path_for_actual, &self.0);
         crate::write_to_file(&path, &self.0);
@@ -101,6 +117,11 @@ impl NormalizedOutput {
     }
 }
 
+/// Returns true if `UPDATE_BASELINES=1`.
+fn update_baselines() -> bool {
+    ::std::env::var("UPDATE_BASELINES").unwrap_or(String::from("0")) == "1"
+}
+
 impl From<String> for NormalizedOutput {
     fn from(s: String) -> Self {
         if s.is_empty() {
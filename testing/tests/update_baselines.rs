@@ -0,0 +1,48 @@
+//! `UPDATE_BASELINES` is a process-wide environment variable, so the test in
+//! this file must stay the only one in this binary.
+
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+use testing::NormalizedOutput;
+
+#[test]
+fn update_baselines() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // `compare_to_file` requires baselines to be inside `CARGO_MANIFEST_DIR`.
+    let target_dir = manifest_dir.join("target");
+    create_dir_all(&target_dir).unwrap();
+    let dir = tempfile::Builder::new()
+        .prefix("update-baselines")
+        .tempdir_in(&target_dir)
+        .unwrap();
+    let path = dir.path().join("fixture.stderr");
+
+    let actual = NormalizedOutput::from(format!(
+        "error: new\n --> {}/fixture.ts:1:1\n",
+        manifest_dir.display()
+    ));
+    let normalized = "error: new\n --> $DIR/fixture.ts:1:1\n";
+
+    // By default, a mismatch fails and the baseline is overwritten.
+    env::remove_var("UPDATE_BASELINES");
+    write(&path, "old\n").unwrap();
+    assert!(actual.clone().compare_to_file(&path).is_err());
+    assert_eq!(read_to_string(&path).unwrap(), normalized);
+
+    // In update mode, a mismatch passes and the baseline is rewritten.
+    write(&path, "old\n").unwrap();
+    env::set_var("UPDATE_BASELINES", "1");
+    let updated = actual.clone().compare_to_file(&path);
+    // Empty output removes the baseline instead of leaving an empty file.
+    let removed = NormalizedOutput::from(String::new()).compare_to_file(&path);
+    env::remove_var("UPDATE_BASELINES");
+
+    assert!(updated.is_ok());
+    assert!(removed.is_ok());
+    assert!(!path.exists());
+    assert!(actual.compare_to_file(&path).is_err());
+    assert_eq!(read_to_string(&path).unwrap(), normalized);
+}